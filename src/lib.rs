@@ -1,9 +1,9 @@
 #![deny(clippy::all)]
 
 use once_cell::sync::Lazy;
-use std::sync::atomic::Ordering;
-use std::sync::Mutex;
-use std::{collections::HashMap, sync::atomic::AtomicU32};
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::{collections::HashMap, time::Duration};
 
 use napi::{
   bindgen_prelude::{AbortSignal, AsyncTask},
@@ -13,23 +13,108 @@ use napi::{
 #[macro_use]
 extern crate napi_derive;
 
+/// Shared, lock-free view of a `Database`'s background maintenance worker, so
+/// `maintenance_status` can be read without contending with the worker loop.
+#[derive(Default)]
+pub struct MaintenanceStatus {
+  running: AtomicBool,
+  paused: AtomicBool,
+  compactions: AtomicU32,
+  last_run_ms: AtomicI64,
+}
+
+#[derive(Clone, Copy)]
+enum MaintenanceCommand {
+  Pause,
+  Resume,
+}
+
 pub struct Database {
+  // Declared before `db` so the snapshots (which borrow `db` for their
+  // lifetime) are dropped first, before the `DB` they point into.
+  snapshots: Mutex<HashMap<u32, Arc<rocksdb::Snapshot<'static>>>>,
   db: rocksdb::DB,
   db_opts: rocksdb::Options,
   filepath: String,
+  maintenance_status: Arc<MaintenanceStatus>,
+  maintenance_control: Mutex<Option<tokio::sync::mpsc::Sender<MaintenanceCommand>>>,
 }
 
 #[napi(object)]
 pub struct Options {
   pub create_if_missing: bool,
   pub keep_log_file_num: u32,
+  pub column_families: Option<Vec<String>>,
 }
 
 static DB_ID: AtomicU32 = AtomicU32::new(0);
+static SNAPSHOT_ID: AtomicU32 = AtomicU32::new(0);
 
-static DATABASE_INSTANCES: Lazy<Mutex<HashMap<u32, Database>>> =
+// Boxed so a `Database`'s address (and the `rocksdb::DB` embedded in it) is
+// stable across inserts into this map. `Snapshot`s borrow into that `DB` and
+// are stored alongside it, in the same `Database`; if the map ever moved a
+// `Database` on rehash (as it would if the value were stored inline), every
+// live snapshot's reference would dangle.
+static DATABASE_INSTANCES: Lazy<Mutex<HashMap<u32, Box<Database>>>> =
   Lazy::new(|| Mutex::new(HashMap::new()));
 
+fn get_db(dbs: &HashMap<u32, Box<Database>>, db_id: u32) -> napi::Result<&Database> {
+  dbs.get(&db_id).map(Box::as_ref).ok_or_else(|| {
+    napi::Error::new(
+      napi::Status::InvalidArg,
+      format!("no database open with id {}", db_id),
+    )
+  })
+}
+
+fn to_napi_error(e: rocksdb::Error) -> napi::Error {
+  napi::Error::new(napi::Status::GenericFailure, format!("{}", e))
+}
+
+/// Resolves an optional column family name to its handle, so every API that
+/// accepts `cf` can fall back to the default keyspace when it is omitted.
+fn resolve_cf<'a>(
+  db: &'a Database,
+  cf: &Option<String>,
+) -> napi::Result<Option<&'a rocksdb::ColumnFamily>> {
+  match cf {
+    None => Ok(None),
+    Some(name) => db.db.cf_handle(name).map(Some).ok_or_else(|| {
+      napi::Error::new(
+        napi::Status::InvalidArg,
+        format!("no column family named \"{}\"", name),
+      )
+    }),
+  }
+}
+
+fn get_item_bytes(
+  db: &Database,
+  key: &[u8],
+  cf: Option<&rocksdb::ColumnFamily>,
+  snapshot: Option<u32>,
+) -> napi::Result<Option<Vec<u8>>> {
+  let (opts, _snapshot) = read_opts_for_snapshot(snapshot, &db.snapshots)?;
+  match cf {
+    Some(cf) => db.db.get_cf_opt(cf, key, &opts),
+    None => db.db.get_opt(key, &opts),
+  }
+  .map_err(to_napi_error)
+}
+
+fn set_item_bytes(
+  db: &Database,
+  key: &[u8],
+  value: &[u8],
+  cf: Option<&rocksdb::ColumnFamily>,
+) -> napi::Result<()> {
+  match cf {
+    Some(cf) => db.db.put_cf(cf, key, value),
+    None => db.db.put(key, value),
+  }
+  .map_err(to_napi_error)
+}
+
 pub struct ConnectTask {
   path: String,
   opts: Options,
@@ -45,16 +130,28 @@ impl Task for ConnectTask {
     db_opts.create_if_missing(self.opts.create_if_missing);
     db_opts.set_keep_log_file_num(self.opts.keep_log_file_num.try_into().unwrap());
 
-    let db = rocksdb::DB::open(&db_opts, &self.path).unwrap();
+    let db = match &self.opts.column_families {
+      Some(cf_names) if !cf_names.is_empty() => {
+        // Without this, `open_cf` requires every listed column family to
+        // already exist on disk, so connecting to a brand-new database with
+        // `column_families` set fails with "Column family not found".
+        db_opts.create_missing_column_families(true);
+        rocksdb::DB::open_cf(&db_opts, &self.path, cf_names).map_err(to_napi_error)?
+      }
+      _ => rocksdb::DB::open(&db_opts, &self.path).map_err(to_napi_error)?,
+    };
     let db_instance = Database {
+      snapshots: Mutex::new(HashMap::new()),
       db,
       db_opts,
       filepath: self.path.clone(),
+      maintenance_status: Arc::new(MaintenanceStatus::default()),
+      maintenance_control: Mutex::new(None),
     };
 
     let db_id = DB_ID.fetch_add(1, Ordering::Relaxed);
     let mut dbs = DATABASE_INSTANCES.lock().unwrap();
-    dbs.insert(db_id, db_instance);
+    dbs.insert(db_id, Box::new(db_instance));
     Ok(db_id)
   }
 
@@ -72,9 +169,371 @@ pub fn connect(
   AsyncTask::with_optional_signal(ConnectTask { path, opts }, abort_signal)
 }
 
+pub struct CreateColumnFamilyTask {
+  db_id: u32,
+  name: String,
+}
+
+#[napi]
+impl Task for CreateColumnFamilyTask {
+  type Output = ();
+  type JsValue = ();
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let dbs = DATABASE_INSTANCES.lock().unwrap();
+    let db = get_db(&dbs, self.db_id)?;
+
+    let opts = rocksdb::Options::default();
+    db.db.create_cf(&self.name, &opts).map_err(to_napi_error)
+  }
+
+  fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+/// Opens a new column family so callers can keep a logically separate keyspace
+/// (indexes, metadata, ...) with its own compaction, instead of prefixing keys.
+#[napi]
+pub fn create_column_family(
+  db_id: u32,
+  name: String,
+  abort_signal: Option<AbortSignal>,
+) -> AsyncTask<CreateColumnFamilyTask> {
+  AsyncTask::with_optional_signal(CreateColumnFamilyTask { db_id, name }, abort_signal)
+}
+
+pub struct DropColumnFamilyTask {
+  db_id: u32,
+  name: String,
+}
+
+#[napi]
+impl Task for DropColumnFamilyTask {
+  type Output = ();
+  type JsValue = ();
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let dbs = DATABASE_INSTANCES.lock().unwrap();
+    let db = get_db(&dbs, self.db_id)?;
+
+    db.db.drop_cf(&self.name).map_err(to_napi_error)
+  }
+
+  fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+#[napi]
+pub fn drop_column_family(
+  db_id: u32,
+  name: String,
+  abort_signal: Option<AbortSignal>,
+) -> AsyncTask<DropColumnFamilyTask> {
+  AsyncTask::with_optional_signal(DropColumnFamilyTask { db_id, name }, abort_signal)
+}
+
+pub struct SnapshotTask {
+  db_id: u32,
+}
+
+#[napi]
+impl Task for SnapshotTask {
+  type Output = u32;
+  type JsValue = u32;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let dbs = DATABASE_INSTANCES.lock().unwrap();
+    let db = get_db(&dbs, self.db_id)?;
+
+    let snapshot = db.db.snapshot();
+    // SAFETY: `Database::snapshots` is declared before `Database::db` so it
+    // is dropped first, before the `DB` this snapshot borrows from.
+    let snapshot: rocksdb::Snapshot<'static> = unsafe { std::mem::transmute(snapshot) };
+
+    let snapshot_id = SNAPSHOT_ID.fetch_add(1, Ordering::Relaxed);
+    db.snapshots
+      .lock()
+      .unwrap()
+      .insert(snapshot_id, Arc::new(snapshot));
+    Ok(snapshot_id)
+  }
+
+  fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+/// Captures a point-in-time-consistent view of the database. Reads that pass
+/// the returned id back in will see this snapshot instead of the live state,
+/// giving JS callers the equivalent of a read transaction without holding the
+/// global mutex across awaits.
+#[napi]
+pub fn snapshot(db_id: u32, abort_signal: Option<AbortSignal>) -> AsyncTask<SnapshotTask> {
+  AsyncTask::with_optional_signal(SnapshotTask { db_id }, abort_signal)
+}
+
+pub struct ReleaseSnapshotTask {
+  db_id: u32,
+  snapshot_id: u32,
+}
+
+#[napi]
+impl Task for ReleaseSnapshotTask {
+  type Output = ();
+  type JsValue = ();
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let dbs = DATABASE_INSTANCES.lock().unwrap();
+    let db = get_db(&dbs, self.db_id)?;
+
+    db.snapshots.lock().unwrap().remove(&self.snapshot_id);
+    Ok(())
+  }
+
+  fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+#[napi]
+pub fn release_snapshot(
+  db_id: u32,
+  snapshot_id: u32,
+  abort_signal: Option<AbortSignal>,
+) -> AsyncTask<ReleaseSnapshotTask> {
+  AsyncTask::with_optional_signal(
+    ReleaseSnapshotTask {
+      db_id,
+      snapshot_id,
+    },
+    abort_signal,
+  )
+}
+
+#[napi(object)]
+pub struct MaintenanceOptions {
+  pub interval_ms: u32,
+  pub tranquility_ms: Option<u32>,
+}
+
+#[napi(object)]
+pub struct MaintenanceStatusResult {
+  pub running: bool,
+  pub paused: bool,
+  pub compactions: u32,
+  pub last_run_ms: Option<i64>,
+}
+
+pub struct StartMaintenanceTask {
+  db_id: u32,
+  opts: MaintenanceOptions,
+}
+
+#[napi]
+impl Task for StartMaintenanceTask {
+  type Output = ();
+  type JsValue = ();
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let dbs = DATABASE_INSTANCES.lock().unwrap();
+    let db = get_db(&dbs, self.db_id)?;
+
+    let mut control_slot = db.maintenance_control.lock().unwrap();
+    if control_slot.is_some() {
+      return Err(napi::Error::new(
+        napi::Status::InvalidArg,
+        "maintenance worker is already running",
+      ));
+    }
+
+    let (tx, mut rx) = tokio::sync::mpsc::channel::<MaintenanceCommand>(8);
+    *control_slot = Some(tx);
+    drop(control_slot);
+
+    let status = db.maintenance_status.clone();
+    status.running.store(true, Ordering::Relaxed);
+    status.paused.store(false, Ordering::Relaxed);
+
+    let db_id = self.db_id;
+    let interval_ms = u64::from(self.opts.interval_ms);
+    let tranquility_ms = u64::from(self.opts.tranquility_ms.unwrap_or(0));
+
+    // `Task::compute` runs on a libuv worker thread, not inside an entered
+    // Tokio runtime, so a bare `tokio::spawn` here would panic. napi-rs's
+    // `bindgen_prelude::spawn` submits the future to its own internally
+    // managed runtime regardless of the calling thread's context.
+    napi::bindgen_prelude::spawn(async move {
+      loop {
+        tokio::select! {
+          _ = tokio::time::sleep(Duration::from_millis(interval_ms)) => {}
+          cmd = rx.recv() => {
+            match cmd {
+              Some(MaintenanceCommand::Pause) => status.paused.store(true, Ordering::Relaxed),
+              Some(MaintenanceCommand::Resume) => status.paused.store(false, Ordering::Relaxed),
+              None => break,
+            }
+            continue;
+          }
+        }
+
+        if status.paused.load(Ordering::Relaxed) {
+          continue;
+        }
+
+        // Run the blocking compaction/flush off the async pool so a slow
+        // compaction cannot starve other napi tasks.
+        let result = tokio::task::spawn_blocking(move || {
+          let dbs = DATABASE_INSTANCES.lock().unwrap();
+          if let Some(db) = dbs.get(&db_id) {
+            db.db.compact_range(None::<&[u8]>, None::<&[u8]>);
+            let _ = db.db.flush();
+          }
+        })
+        .await;
+
+        if result.is_ok() {
+          let now_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as i64)
+            .unwrap_or(0);
+          status.last_run_ms.store(now_ms, Ordering::Relaxed);
+          status.compactions.fetch_add(1, Ordering::Relaxed);
+        }
+
+        if tranquility_ms > 0 {
+          tokio::time::sleep(Duration::from_millis(tranquility_ms)).await;
+        }
+      }
+
+      status.running.store(false, Ordering::Relaxed);
+    });
+
+    Ok(())
+  }
+
+  fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+/// Starts a long-lived background worker that periodically compacts and
+/// flushes the database, reporting its own active/idle/dead state through
+/// `maintenance_status` instead of the caller having to poll compaction APIs
+/// directly.
+#[napi]
+pub fn start_maintenance(
+  db_id: u32,
+  opts: MaintenanceOptions,
+  abort_signal: Option<AbortSignal>,
+) -> AsyncTask<StartMaintenanceTask> {
+  AsyncTask::with_optional_signal(StartMaintenanceTask { db_id, opts }, abort_signal)
+}
+
+pub struct SetMaintenanceCommandTask {
+  db_id: u32,
+  command: MaintenanceCommand,
+}
+
+#[napi]
+impl Task for SetMaintenanceCommandTask {
+  type Output = ();
+  type JsValue = ();
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let dbs = DATABASE_INSTANCES.lock().unwrap();
+    let db = get_db(&dbs, self.db_id)?;
+
+    let control = db.maintenance_control.lock().unwrap();
+    let tx = control.as_ref().ok_or_else(|| {
+      napi::Error::new(
+        napi::Status::InvalidArg,
+        "maintenance worker is not running",
+      )
+    })?;
+    tx.try_send(self.command)
+      .map_err(|e| napi::Error::new(napi::Status::GenericFailure, format!("{}", e)))
+  }
+
+  fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+#[napi]
+pub fn pause_maintenance(
+  db_id: u32,
+  abort_signal: Option<AbortSignal>,
+) -> AsyncTask<SetMaintenanceCommandTask> {
+  AsyncTask::with_optional_signal(
+    SetMaintenanceCommandTask {
+      db_id,
+      command: MaintenanceCommand::Pause,
+    },
+    abort_signal,
+  )
+}
+
+#[napi]
+pub fn resume_maintenance(
+  db_id: u32,
+  abort_signal: Option<AbortSignal>,
+) -> AsyncTask<SetMaintenanceCommandTask> {
+  AsyncTask::with_optional_signal(
+    SetMaintenanceCommandTask {
+      db_id,
+      command: MaintenanceCommand::Resume,
+    },
+    abort_signal,
+  )
+}
+
+pub struct MaintenanceStatusTask {
+  db_id: u32,
+}
+
+#[napi]
+impl Task for MaintenanceStatusTask {
+  type Output = MaintenanceStatusResult;
+  type JsValue = MaintenanceStatusResult;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let dbs = DATABASE_INSTANCES.lock().unwrap();
+    let db = get_db(&dbs, self.db_id)?;
+    let status = &db.maintenance_status;
+
+    let last_run_ms = status.last_run_ms.load(Ordering::Relaxed);
+    Ok(MaintenanceStatusResult {
+      running: status.running.load(Ordering::Relaxed),
+      paused: status.paused.load(Ordering::Relaxed),
+      compactions: status.compactions.load(Ordering::Relaxed),
+      last_run_ms: if last_run_ms == 0 {
+        None
+      } else {
+        Some(last_run_ms)
+      },
+    })
+  }
+
+  fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+#[napi]
+pub fn maintenance_status(
+  db_id: u32,
+  abort_signal: Option<AbortSignal>,
+) -> AsyncTask<MaintenanceStatusTask> {
+  AsyncTask::with_optional_signal(MaintenanceStatusTask { db_id }, abort_signal)
+}
+
 pub struct GetItemTask {
   db_id: u32,
   key: String,
+  cf: Option<String>,
+  snapshot: Option<u32>,
 }
 
 #[napi]
@@ -84,15 +543,14 @@ impl Task for GetItemTask {
 
   fn compute(&mut self) -> napi::Result<Self::Output> {
     let dbs = DATABASE_INSTANCES.lock().unwrap();
-    let db = dbs.get(&self.db_id).unwrap();
-
-    match db.db.get(&self.key) {
-      Ok(Some(value)) => Ok(Some(String::from_utf8(value).unwrap())),
-      Ok(None) => Ok(None),
-      Err(e) => Err(napi::Error::new(
-        napi::Status::GenericFailure,
-        format!("{}", e),
-      )),
+    let db = get_db(&dbs, self.db_id)?;
+    let cf = resolve_cf(db, &self.cf)?;
+
+    match get_item_bytes(db, self.key.as_bytes(), cf, self.snapshot)? {
+      Some(value) => String::from_utf8(value)
+        .map(Some)
+        .map_err(|e| napi::Error::new(napi::Status::GenericFailure, format!("{}", e))),
+      None => Ok(None),
     }
   }
 
@@ -101,19 +559,169 @@ impl Task for GetItemTask {
   }
 }
 
+/// Pass a `snapshot` id from `snapshot()` to read the point-in-time view it
+/// pinned instead of the current state of the database.
 #[napi]
 pub fn get_item(
   db_id: u32,
   key: String,
+  cf: Option<String>,
+  snapshot: Option<u32>,
   abort_signal: Option<AbortSignal>,
 ) -> AsyncTask<GetItemTask> {
-  AsyncTask::with_optional_signal(GetItemTask { db_id, key }, abort_signal)
+  AsyncTask::with_optional_signal(
+    GetItemTask {
+      db_id,
+      key,
+      cf,
+      snapshot,
+    },
+    abort_signal,
+  )
+}
+
+pub struct GetItemBufferTask {
+  db_id: u32,
+  key: napi::bindgen_prelude::Buffer,
+  cf: Option<String>,
+  snapshot: Option<u32>,
+}
+
+#[napi]
+impl Task for GetItemBufferTask {
+  type Output = Option<Vec<u8>>;
+  type JsValue = Option<napi::bindgen_prelude::Buffer>;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let dbs = DATABASE_INSTANCES.lock().unwrap();
+    let db = get_db(&dbs, self.db_id)?;
+    let cf = resolve_cf(db, &self.cf)?;
+
+    get_item_bytes(db, self.key.as_ref(), cf, self.snapshot)
+  }
+
+  fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output.map(Into::into))
+  }
+}
+
+/// Binary counterpart of `get_item` that returns the raw bytes without attempting
+/// UTF-8 decoding, so arbitrary byte values round-trip losslessly.
+#[napi]
+pub fn get_item_buffer(
+  db_id: u32,
+  key: napi::bindgen_prelude::Buffer,
+  cf: Option<String>,
+  snapshot: Option<u32>,
+  abort_signal: Option<AbortSignal>,
+) -> AsyncTask<GetItemBufferTask> {
+  AsyncTask::with_optional_signal(
+    GetItemBufferTask {
+      db_id,
+      key,
+      cf,
+      snapshot,
+    },
+    abort_signal,
+  )
+}
+
+/// Builds `ReadOptions` pinned to the given snapshot, if any. Looks the
+/// snapshot up through a brief, independent lock acquisition and clones out
+/// an `Arc` rather than holding `Database::snapshots` locked for as long as
+/// the returned `ReadOptions` is in use — otherwise a slow scan reading from
+/// a snapshot would serialize every other snapshot-related call on the same
+/// database for its entire duration. Callers must keep the returned `Arc`
+/// alive for as long as they use the `ReadOptions`, since it borrows from it.
+fn read_opts_for_snapshot(
+  snapshot_id: Option<u32>,
+  snapshots: &Mutex<HashMap<u32, Arc<rocksdb::Snapshot<'static>>>>,
+) -> napi::Result<(rocksdb::ReadOptions, Option<Arc<rocksdb::Snapshot<'static>>>)> {
+  let mut opts = rocksdb::ReadOptions::default();
+  let held = if let Some(id) = snapshot_id {
+    let snapshot = snapshots.lock().unwrap().get(&id).cloned().ok_or_else(|| {
+      napi::Error::new(
+        napi::Status::InvalidArg,
+        format!("no snapshot with id {}", id),
+      )
+    })?;
+    opts.set_snapshot(&snapshot);
+    Some(snapshot)
+  } else {
+    None
+  };
+  Ok((opts, held))
+}
+
+pub struct MultiGetTask {
+  db_id: u32,
+  keys: Vec<String>,
+  cf: Option<String>,
+  snapshot: Option<u32>,
+}
+
+#[napi]
+impl Task for MultiGetTask {
+  type Output = Vec<Option<String>>;
+  type JsValue = Vec<Option<String>>;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let dbs = DATABASE_INSTANCES.lock().unwrap();
+    let db = get_db(&dbs, self.db_id)?;
+    let cf = resolve_cf(db, &self.cf)?;
+    let (opts, _snapshot) = read_opts_for_snapshot(self.snapshot, &db.snapshots)?;
+
+    let results: Vec<Result<Option<Vec<u8>>, rocksdb::Error>> = match cf {
+      Some(cf) => db
+        .db
+        .multi_get_cf_opt(self.keys.iter().map(|key| (cf, key.as_bytes())), &opts),
+      None => db.db.multi_get_opt(self.keys.iter().map(String::as_bytes), &opts),
+    };
+
+    results
+      .into_iter()
+      .map(|result| {
+        result.map_err(to_napi_error)?.map_or(Ok(None), |value| {
+          String::from_utf8(value)
+            .map(Some)
+            .map_err(|e| napi::Error::new(napi::Status::GenericFailure, format!("{}", e)))
+        })
+      })
+      .collect()
+  }
+
+  fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+/// Looks up many keys in a single lock acquisition instead of one task queue
+/// round-trip per key. Pass a `snapshot` id from `snapshot()` to read a
+/// consistent point-in-time view even while concurrent writes happen.
+#[napi]
+pub fn multi_get(
+  db_id: u32,
+  keys: Vec<String>,
+  cf: Option<String>,
+  snapshot: Option<u32>,
+  abort_signal: Option<AbortSignal>,
+) -> AsyncTask<MultiGetTask> {
+  AsyncTask::with_optional_signal(
+    MultiGetTask {
+      db_id,
+      keys,
+      cf,
+      snapshot,
+    },
+    abort_signal,
+  )
 }
 
 pub struct SetItemTask {
   db_id: u32,
   key: String,
   value: String,
+  cf: Option<String>,
 }
 
 #[napi]
@@ -123,10 +731,10 @@ impl Task for SetItemTask {
 
   fn compute(&mut self) -> napi::Result<Self::Output> {
     let dbs = DATABASE_INSTANCES.lock().unwrap();
-    let db = dbs.get(&self.db_id).unwrap();
+    let db = get_db(&dbs, self.db_id)?;
+    let cf = resolve_cf(db, &self.cf)?;
 
-    db.db.put(&self.key, &self.value).unwrap();
-    Ok(())
+    set_item_bytes(db, self.key.as_bytes(), self.value.as_bytes(), cf)
   }
 
   fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
@@ -139,14 +747,223 @@ pub fn set_item(
   db_id: u32,
   key: String,
   value: String,
+  cf: Option<String>,
   abort_signal: Option<AbortSignal>,
 ) -> AsyncTask<SetItemTask> {
-  AsyncTask::with_optional_signal(SetItemTask { db_id, key, value }, abort_signal)
+  AsyncTask::with_optional_signal(SetItemTask { db_id, key, value, cf }, abort_signal)
+}
+
+pub struct SetItemBufferTask {
+  db_id: u32,
+  key: napi::bindgen_prelude::Buffer,
+  value: napi::bindgen_prelude::Buffer,
+  cf: Option<String>,
+}
+
+#[napi]
+impl Task for SetItemBufferTask {
+  type Output = ();
+  type JsValue = ();
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let dbs = DATABASE_INSTANCES.lock().unwrap();
+    let db = get_db(&dbs, self.db_id)?;
+    let cf = resolve_cf(db, &self.cf)?;
+
+    set_item_bytes(db, self.key.as_ref(), self.value.as_ref(), cf)
+  }
+
+  fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+/// Binary counterpart of `set_item` that stores the given bytes as-is without any
+/// charset conversion.
+#[napi]
+pub fn set_item_buffer(
+  db_id: u32,
+  key: napi::bindgen_prelude::Buffer,
+  value: napi::bindgen_prelude::Buffer,
+  cf: Option<String>,
+  abort_signal: Option<AbortSignal>,
+) -> AsyncTask<SetItemBufferTask> {
+  AsyncTask::with_optional_signal(SetItemBufferTask { db_id, key, value, cf }, abort_signal)
+}
+
+#[napi(object)]
+pub struct KeyValue {
+  pub key: String,
+  pub value: String,
+}
+
+pub struct SetItemManyTask {
+  db_id: u32,
+  items: Vec<KeyValue>,
+  cf: Option<String>,
+}
+
+#[napi]
+impl Task for SetItemManyTask {
+  type Output = ();
+  type JsValue = ();
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let dbs = DATABASE_INSTANCES.lock().unwrap();
+    let db = get_db(&dbs, self.db_id)?;
+    let cf = resolve_cf(db, &self.cf)?;
+
+    let mut batch = rocksdb::WriteBatch::default();
+    for item in &self.items {
+      match cf {
+        Some(cf) => batch.put_cf(cf, &item.key, &item.value),
+        None => batch.put(&item.key, &item.value),
+      }
+    }
+    db.db.write(batch).map_err(to_napi_error)
+  }
+
+  fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+/// Convenience alternative to `write_batch` for the common case of setting several
+/// keys at once, applied as a single atomic `WriteBatch`.
+#[napi]
+pub fn set_item_many(
+  db_id: u32,
+  items: Vec<KeyValue>,
+  cf: Option<String>,
+  abort_signal: Option<AbortSignal>,
+) -> AsyncTask<SetItemManyTask> {
+  AsyncTask::with_optional_signal(SetItemManyTask { db_id, items, cf }, abort_signal)
+}
+
+#[napi(string_enum)]
+pub enum BatchOperationType {
+  Put,
+  Delete,
+}
+
+#[napi(object)]
+pub struct BatchOperation {
+  #[napi(js_name = "type")]
+  pub op_type: BatchOperationType,
+  pub key: String,
+  pub value: Option<String>,
+}
+
+#[napi(object)]
+pub struct WriteOptions {
+  pub sync: Option<bool>,
+}
+
+pub struct WriteBatchTask {
+  db_id: u32,
+  ops: Vec<BatchOperation>,
+  write_opts: Option<WriteOptions>,
+  cf: Option<String>,
+}
+
+#[napi]
+impl Task for WriteBatchTask {
+  type Output = ();
+  type JsValue = ();
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let dbs = DATABASE_INSTANCES.lock().unwrap();
+    let db = get_db(&dbs, self.db_id)?;
+    let cf = resolve_cf(db, &self.cf)?;
+
+    let mut batch = rocksdb::WriteBatch::default();
+    for op in &self.ops {
+      match op.op_type {
+        BatchOperationType::Put => {
+          let value = op.value.as_ref().ok_or_else(|| {
+            napi::Error::new(
+              napi::Status::InvalidArg,
+              "`value` is required for a \"put\" operation",
+            )
+          })?;
+          match cf {
+            Some(cf) => batch.put_cf(cf, &op.key, value),
+            None => batch.put(&op.key, value),
+          }
+        }
+        BatchOperationType::Delete => match cf {
+          Some(cf) => batch.delete_cf(cf, &op.key),
+          None => batch.delete(&op.key),
+        },
+      }
+    }
+
+    let mut opts = rocksdb::WriteOptions::default();
+    if let Some(write_opts) = &self.write_opts {
+      if let Some(sync) = write_opts.sync {
+        opts.set_sync(sync);
+      }
+    }
+
+    db.db.write_opt(batch, &opts).map_err(to_napi_error)
+  }
+
+  fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+/// Applies a list of put/delete operations as a single atomic, crash-consistent
+/// `WriteBatch` instead of committing each one independently.
+#[napi]
+pub fn write_batch(
+  db_id: u32,
+  ops: Vec<BatchOperation>,
+  write_opts: Option<WriteOptions>,
+  cf: Option<String>,
+  abort_signal: Option<AbortSignal>,
+) -> AsyncTask<WriteBatchTask> {
+  AsyncTask::with_optional_signal(
+    WriteBatchTask {
+      db_id,
+      ops,
+      write_opts,
+      cf,
+    },
+    abort_signal,
+  )
+}
+
+fn get_keys_bytes(
+  db: &Database,
+  prefix: Option<&[u8]>,
+  cf: Option<&rocksdb::ColumnFamily>,
+) -> napi::Result<Vec<Vec<u8>>> {
+  let iter = match cf {
+    Some(cf) => db.db.iterator_cf(cf, rocksdb::IteratorMode::Start),
+    None => db.db.iterator(rocksdb::IteratorMode::Start),
+  };
+  let mut keys: Vec<Vec<u8>> = vec![];
+
+  for item in iter {
+    let (key, _) = item.map_err(to_napi_error)?;
+
+    if let Some(prefix) = prefix {
+      if !key.starts_with(prefix) {
+        continue;
+      }
+    }
+
+    keys.push(key.to_vec());
+  }
+
+  Ok(keys)
 }
 
 pub struct GetKeysTask {
   db_id: u32,
   prefix: Option<String>,
+  cf: Option<String>,
 }
 
 #[napi]
@@ -156,32 +973,186 @@ impl Task for GetKeysTask {
 
   fn compute(&mut self) -> napi::Result<Self::Output> {
     let dbs = DATABASE_INSTANCES.lock().unwrap();
-    let db = dbs.get(&self.db_id).unwrap();
+    let db = get_db(&dbs, self.db_id)?;
+    let cf = resolve_cf(db, &self.cf)?;
+
+    get_keys_bytes(db, self.prefix.as_deref().map(str::as_bytes), cf)?
+      .into_iter()
+      .map(|key| {
+        String::from_utf8(key)
+          .map_err(|e| napi::Error::new(napi::Status::GenericFailure, format!("{}", e)))
+      })
+      .collect()
+  }
+
+  fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+#[napi]
+pub fn get_keys(
+  db_id: u32,
+  prefix: Option<String>,
+  cf: Option<String>,
+  abort_signal: Option<AbortSignal>,
+) -> AsyncTask<GetKeysTask> {
+  AsyncTask::with_optional_signal(GetKeysTask { db_id, prefix, cf }, abort_signal)
+}
+
+pub struct GetKeysBufferTask {
+  db_id: u32,
+  prefix: Option<napi::bindgen_prelude::Buffer>,
+  cf: Option<String>,
+}
+
+#[napi]
+impl Task for GetKeysBufferTask {
+  type Output = Vec<Vec<u8>>;
+  type JsValue = Vec<napi::bindgen_prelude::Buffer>;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let dbs = DATABASE_INSTANCES.lock().unwrap();
+    let db = get_db(&dbs, self.db_id)?;
+    let cf = resolve_cf(db, &self.cf)?;
+
+    get_keys_bytes(db, self.prefix.as_deref(), cf)
+  }
+
+  fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output.into_iter().map(Into::into).collect())
+  }
+}
+
+/// Binary counterpart of `get_keys` that returns raw key bytes, so keys that are
+/// not valid UTF-8 (which `get_keys` rejects with an error) round-trip losslessly.
+#[napi]
+pub fn get_keys_buffer(
+  db_id: u32,
+  prefix: Option<napi::bindgen_prelude::Buffer>,
+  cf: Option<String>,
+  abort_signal: Option<AbortSignal>,
+) -> AsyncTask<GetKeysBufferTask> {
+  AsyncTask::with_optional_signal(GetKeysBufferTask { db_id, prefix, cf }, abort_signal)
+}
+
+#[napi(object)]
+pub struct RangeOptions {
+  pub start: Option<String>,
+  pub end: Option<String>,
+  pub prefix: Option<String>,
+  pub limit: Option<u32>,
+  pub reverse: Option<bool>,
+  pub values: Option<bool>,
+}
+
+#[napi(object)]
+pub struct RangeItem {
+  pub key: String,
+  pub value: Option<String>,
+}
+
+#[napi(object)]
+pub struct RangeResult {
+  pub items: Vec<RangeItem>,
+  pub next_start: Option<String>,
+}
+
+pub struct RangeTask {
+  db_id: u32,
+  opts: RangeOptions,
+  cf: Option<String>,
+  snapshot: Option<u32>,
+}
+
+#[napi]
+impl Task for RangeTask {
+  type Output = RangeResult;
+  type JsValue = RangeResult;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let dbs = DATABASE_INSTANCES.lock().unwrap();
+    let db = get_db(&dbs, self.db_id)?;
+    let cf = resolve_cf(db, &self.cf)?;
+
+    let reverse = self.opts.reverse.unwrap_or(false);
+    let direction = if reverse {
+      rocksdb::Direction::Reverse
+    } else {
+      rocksdb::Direction::Forward
+    };
+    let mode = match (&self.opts.start, &self.opts.prefix) {
+      (Some(start), _) => rocksdb::IteratorMode::From(start.as_bytes(), direction),
+      // No explicit `start`, but a `prefix` was given on a forward scan: seek
+      // straight to it instead of scanning the whole keyspace from the start.
+      // A reverse scan starts from the end, since every key carrying the
+      // prefix sorts after the prefix itself.
+      (None, Some(prefix)) if !reverse => rocksdb::IteratorMode::From(prefix.as_bytes(), direction),
+      (None, _) if reverse => rocksdb::IteratorMode::End,
+      (None, _) => rocksdb::IteratorMode::Start,
+    };
+    let limit = self.opts.limit.unwrap_or(u32::MAX) as usize;
+    let include_values = self.opts.values.unwrap_or(true);
 
-    let iter = db.db.iterator(rocksdb::IteratorMode::Start);
-    let mut keys: Vec<String> = vec![];
+    let (read_opts, _snapshot) = read_opts_for_snapshot(self.snapshot, &db.snapshots)?;
+    let iter = match cf {
+      Some(cf) => db.db.iterator_cf_opt(cf, read_opts, mode),
+      None => db.db.iterator_opt(mode, read_opts),
+    };
+    let mut items: Vec<RangeItem> = vec![];
+    let mut next_start: Option<String> = None;
+    let mut entered_prefix = false;
 
     for item in iter {
-      match item {
-        Ok((key, _)) => {
-          if let Some(prefix) = &self.prefix {
-            if !key.starts_with(prefix.as_bytes()) {
-              continue;
-            }
-          }
+      let (key, value) = item.map_err(to_napi_error)?;
 
-          keys.push(key.to_vec().into_iter().map(|c| c as char).collect());
+      if let Some(prefix) = &self.opts.prefix {
+        if key.starts_with(prefix.as_bytes()) {
+          entered_prefix = true;
+        } else if entered_prefix {
+          // We were inside the matching region and just left it.
+          break;
+        } else {
+          // Haven't reached the matching region yet (e.g. `start` was
+          // omitted and the scan began before `prefix`).
+          continue;
         }
-        Err(e) => {
-          return Err(napi::Error::new(
-            napi::Status::GenericFailure,
-            format!("{}", e),
-          ));
+      }
+
+      if let Some(end) = &self.opts.end {
+        let past_end = if reverse {
+          key.as_ref() <= end.as_bytes()
+        } else {
+          key.as_ref() >= end.as_bytes()
+        };
+        if past_end {
+          break;
         }
       }
+
+      if items.len() >= limit {
+        next_start = Some(
+          String::from_utf8(key.to_vec())
+            .map_err(|e| napi::Error::new(napi::Status::GenericFailure, format!("{}", e)))?,
+        );
+        break;
+      }
+
+      let key = String::from_utf8(key.to_vec())
+        .map_err(|e| napi::Error::new(napi::Status::GenericFailure, format!("{}", e)))?;
+      let value = if include_values {
+        Some(
+          String::from_utf8(value.to_vec())
+            .map_err(|e| napi::Error::new(napi::Status::GenericFailure, format!("{}", e)))?,
+        )
+      } else {
+        None
+      };
+
+      items.push(RangeItem { key, value });
     }
 
-    Ok(keys)
+    Ok(RangeResult { items, next_start })
   }
 
   fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
@@ -189,18 +1160,35 @@ impl Task for GetKeysTask {
   }
 }
 
+/// Paginated range scan modeled on K2V-style range queries: seeks to `start`
+/// (or the end, when `reverse` is set), stops at the exclusive `end` bound or
+/// once `prefix` no longer matches, and caps the page at `limit` items. The
+/// returned `nextStart` is the first key not included in the page, so callers
+/// can resume by passing it back as `start`. Pass a `snapshot` id from
+/// `snapshot()` to page through a consistent point-in-time view.
 #[napi]
-pub fn get_keys(
+pub fn range(
   db_id: u32,
-  prefix: Option<String>,
+  opts: RangeOptions,
+  cf: Option<String>,
+  snapshot: Option<u32>,
   abort_signal: Option<AbortSignal>,
-) -> AsyncTask<GetKeysTask> {
-  AsyncTask::with_optional_signal(GetKeysTask { db_id, prefix }, abort_signal)
+) -> AsyncTask<RangeTask> {
+  AsyncTask::with_optional_signal(
+    RangeTask {
+      db_id,
+      opts,
+      cf,
+      snapshot,
+    },
+    abort_signal,
+  )
 }
 
 pub struct RemoveItemTask {
   db_id: u32,
   key: String,
+  cf: Option<String>,
 }
 
 #[napi]
@@ -210,10 +1198,14 @@ impl Task for RemoveItemTask {
 
   fn compute(&mut self) -> napi::Result<Self::Output> {
     let dbs = DATABASE_INSTANCES.lock().unwrap();
-    let db = dbs.get(&self.db_id).unwrap();
+    let db = get_db(&dbs, self.db_id)?;
+    let cf = resolve_cf(db, &self.cf)?;
 
-    db.db.delete(&self.key).unwrap();
-    Ok(())
+    match cf {
+      Some(cf) => db.db.delete_cf(cf, &self.key),
+      None => db.db.delete(&self.key),
+    }
+    .map_err(to_napi_error)
   }
 
   fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
@@ -225,9 +1217,19 @@ impl Task for RemoveItemTask {
 pub fn remove_item(
   db_id: u32,
   key: String,
+  cf: Option<String>,
   abort_signal: Option<AbortSignal>,
 ) -> AsyncTask<RemoveItemTask> {
-  AsyncTask::with_optional_signal(RemoveItemTask { db_id, key }, abort_signal)
+  AsyncTask::with_optional_signal(RemoveItemTask { db_id, key, cf }, abort_signal)
+}
+
+fn take_db(dbs: &mut HashMap<u32, Box<Database>>, db_id: u32) -> napi::Result<Box<Database>> {
+  dbs.remove(&db_id).ok_or_else(|| {
+    napi::Error::new(
+      napi::Status::InvalidArg,
+      format!("no database open with id {}", db_id),
+    )
+  })
 }
 
 pub struct CloseTask {
@@ -241,11 +1243,10 @@ impl Task for CloseTask {
 
   fn compute(&mut self) -> napi::Result<Self::Output> {
     let mut dbs = DATABASE_INSTANCES.lock().unwrap();
-    let db = dbs.get(&self.db_id).unwrap();
-    println!("Destroying db: {}", &db.filepath);
-    let _ = rocksdb::DB::destroy(&db.db_opts, &db.filepath);
-    dbs.remove(&self.db_id);
-    Ok(())
+    let db = take_db(&mut dbs, self.db_id)?;
+    drop(dbs);
+
+    db.db.flush().map_err(to_napi_error)
   }
 
   fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
@@ -253,7 +1254,87 @@ impl Task for CloseTask {
   }
 }
 
+/// Drops the database handle, leaving its files on disk intact. Callers that
+/// previously relied on `close()` to wipe the database should call `destroy()`
+/// explicitly instead.
 #[napi]
 pub fn close(db_id: u32, abort_signal: Option<AbortSignal>) -> AsyncTask<CloseTask> {
   AsyncTask::with_optional_signal(CloseTask { db_id }, abort_signal)
 }
+
+pub struct DestroyTask {
+  db_id: u32,
+}
+
+#[napi]
+impl Task for DestroyTask {
+  type Output = ();
+  type JsValue = ();
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let mut dbs = DATABASE_INSTANCES.lock().unwrap();
+    let db = take_db(&mut dbs, self.db_id)?;
+    drop(dbs);
+
+    // `db` is dropped here, closing the handle, before `DB::destroy` wipes
+    // the files it was pointing at.
+    let db_opts = db.db_opts.clone();
+    let filepath = db.filepath.clone();
+    drop(db);
+    rocksdb::DB::destroy(&db_opts, &filepath).map_err(to_napi_error)
+  }
+
+  fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+/// Permanently deletes the database's files on disk. This is the destructive
+/// operation `close()` used to perform implicitly; call this explicitly when
+/// that behavior is actually wanted.
+#[napi]
+pub fn destroy(db_id: u32, abort_signal: Option<AbortSignal>) -> AsyncTask<DestroyTask> {
+  AsyncTask::with_optional_signal(DestroyTask { db_id }, abort_signal)
+}
+
+#[napi(object)]
+pub struct FlushOptions {
+  pub wal: Option<bool>,
+}
+
+pub struct FlushTask {
+  db_id: u32,
+  opts: Option<FlushOptions>,
+}
+
+#[napi]
+impl Task for FlushTask {
+  type Output = ();
+  type JsValue = ();
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let dbs = DATABASE_INSTANCES.lock().unwrap();
+    let db = get_db(&dbs, self.db_id)?;
+
+    let flush_wal = self.opts.as_ref().and_then(|opts| opts.wal).unwrap_or(false);
+    if flush_wal {
+      db.db.flush_wal(true).map_err(to_napi_error)?;
+    }
+    db.db.flush().map_err(to_napi_error)
+  }
+
+  fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+/// Forces a flush of the memtable (and, with `{ wal: true }`, the write-ahead
+/// log) to disk, so callers can guarantee durability before shutdown.
+#[napi]
+pub fn flush(
+  db_id: u32,
+  opts: Option<FlushOptions>,
+  abort_signal: Option<AbortSignal>,
+) -> AsyncTask<FlushTask> {
+  AsyncTask::with_optional_signal(FlushTask { db_id, opts }, abort_signal)
+}